@@ -34,12 +34,13 @@ fn main() -> ! {
     // Set up the DHT20 sensor
     println!("Setting up DHT20 sensor...");
     let mut delay = Delay::new();
-    let mut dht20 = dht20::Dht20::new(i2c);
-
-    if let Err(e) = dht20.init(&mut delay) {
-        println!("Failed to initialize the DHT20 sensor: {:?}", e);
-        loop {}
-    }
+    let mut dht20 = match dht20::Dht20Uninit::new(i2c).init(&mut delay) {
+        Ok(dht20) => dht20,
+        Err(e) => {
+            println!("Failed to initialize the DHT20 sensor: {:?}", e);
+            loop {}
+        }
+    };
     println!("Done.");
 
     loop {
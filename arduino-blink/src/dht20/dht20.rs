@@ -31,13 +31,77 @@ impl DHTReading {
     pub fn temperature_fahrenheit(&self) -> f32 {
         self.temperature * 9.0 / 5.0 + 32.0
     }
+
+    // dew point in degrees Celsius, derived from the temperature and humidity
+    pub fn dew_point_celsius(&self) -> f32 {
+        utils::dew_point_celsius(self.temperature, self.humidity)
+    }
+
+    // heat index ("feels like" temperature) in degrees Fahrenheit
+    pub fn heat_index_fahrenheit(&self) -> f32 {
+        utils::heat_index_fahrenheit(self.temperature_fahrenheit(), self.humidity)
+    }
 }
 
 #[derive(Debug)]
 pub enum DHT20Error<E> {
     I2C(E),
     CrcMismatch,
-    NotInitialized,
+    Timeout,
+    NotCalibrated,
+}
+
+// Decoded view of the DHT20 status byte returned by the CheckStatus opcode.
+// Mirrors the named-flag approach the AHT20 driver takes, so the rest of the
+// driver can ask `status.busy()` / `status.calibration_enabled()` rather than
+// masking magic bits inline.
+#[derive(Debug, Clone, Copy)]
+pub struct Status(u8);
+
+impl Status {
+    const BUSY: u8 = 0x80; // bit 7: a measurement is in progress
+    const CALIBRATION_ENABLED: u8 = 0x08; // bit 3: factory calibration loaded
+    const MODE_SHIFT: u8 = 5; // bits 6:5: current workmode
+    const MODE_MASK: u8 = 0b11;
+
+    // wrap a raw status byte
+    pub fn from_byte(byte: u8) -> Self {
+        Self(byte)
+    }
+
+    // true while the sensor is still taking a measurement
+    pub fn busy(&self) -> bool {
+        self.0 & Self::BUSY != 0
+    }
+
+    // the two-bit workmode field (bits 6:5)
+    pub fn mode(&self) -> u8 {
+        (self.0 >> Self::MODE_SHIFT) & Self::MODE_MASK
+    }
+
+    // true once the sensor's calibration has come up
+    pub fn calibration_enabled(&self) -> bool {
+        self.0 & Self::CALIBRATION_ENABLED != 0
+    }
+}
+
+// Bounds the ready-polling loop so a wedged or disconnected sensor can't hang
+// the caller forever. `max_attempts` caps how many times we poll the status
+// bit; `poll_interval_ms` is how long we back off between polls. The defaults
+// leave ~100ms of headroom beyond the 80ms minimum measurement window.
+#[derive(Debug, Clone, Copy)]
+pub struct PollConfig {
+    pub max_attempts: u32,
+    pub poll_interval_ms: u32,
+}
+
+impl Default for PollConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            poll_interval_ms: 10,
+        }
+    }
 }
 
 impl<E> From<E> for DHT20Error<E> {
@@ -50,101 +114,86 @@ impl<E> From<E> for DHT20Error<E> {
 enum OpCode {
     CheckStatus = 0x71,
     TriggerMeasurement = 0xAC,
-    StatusReady = 0x80,
 }
 
 const I2C_ADDRESS: SevenBitAddress = 0x38; // DHT20 default I2C address per datasheet
 const RESET_REGISTERS: [u8; 3] = [0x1B, 0x1C, 0x1E];
 
-pub struct Dht20<I2C> {
+// Uninitialized handle for the DHT20 sensor. The sensor cannot take a
+// measurement until it has been powered up and had its reset-register
+// sequence run, so that work is represented as a distinct type: the only
+// thing you can do with a `Dht20Uninit` is consume it with `init`, which
+// hands back a `Dht20` carrying the measurement methods. This pushes the
+// use-before-init mistake out of runtime and into the type system.
+pub struct Dht20Uninit<I2C> {
     i2c: I2C,
     address: SevenBitAddress,
-    initialized: bool,
+    poll: PollConfig,
 }
 
-impl<I2C, E> Dht20<I2C>
+impl<I2C, E> Dht20Uninit<I2C>
 where
     I2C: I2c<Error = E>,
 {
-    // constructor for Dht20 struct
+    // constructor for the uninitialized handle
     pub fn new(i2c: I2C) -> Self {
+        Self::with_config(i2c, PollConfig::default())
+    }
+
+    // constructor taking an explicit ready-polling budget, for callers that
+    // want to trade responsiveness against tolerance for a slow sensor
+    pub fn with_config(i2c: I2C, poll: PollConfig) -> Self {
         Self {
             i2c, // dependency injection; receive the I2C instance
             address: I2C_ADDRESS,
-            initialized: false,
+            poll,
         }
     }
 
-    // initialize the sensor, return nothing
-    pub fn init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), DHT20Error<E>> {
+    // initialize the sensor, consuming the uninitialized handle and
+    // returning a ready-to-use `Dht20`
+    pub fn init<D: DelayNs>(mut self, delay: &mut D) -> Result<Dht20<I2C>, DHT20Error<E>> {
         delay.delay_ms(100); // wait for sensor to power up, no less than 100ms
 
-        self.check_init(delay)
-    }
-
-    // request a reading from the sensor
-    // returns a DHTReading struct containing the temperature and humidity
-    pub fn take_reading<D: DelayNs>(&mut self, delay: &mut D) -> Result<DHTReading, DHT20Error<E>> {
-        if !self.initialized {
-            return Err(DHT20Error::NotInitialized);
-        }
-
-        self.trigger_measurement(delay)?; // trigger the measurement
-
-        self.wait_for_ready(delay)?; // wait for measurement to be ready
-
-        let data = self.read_measurement()?;
-
-        // extract the humidity and temperature readings from the data
-        let (raw_humidity, raw_temperature) = utils::extract_readings(&data);
-
-        // convert the raw readings to percentage, Celsius
-        let humidity = utils::convert_humidity(raw_humidity);
-        let temperature = utils::convert_temperature(raw_temperature);
-
-        // return the readings as a DHTReading struct
-        Ok(DHTReading::new(temperature, humidity))
-    }
-
-    pub fn read_raw<D: DelayNs>(&mut self, delay: &mut D) -> Result<[u8; 6], DHT20Error<E>> {
-        if !self.initialized {
-            return Err(DHT20Error::NotInitialized);
-        }
-
-        self.trigger_measurement(delay)?; // trigger the measurement
-
-        self.wait_for_ready(delay)?; // wait for measurement to be ready
-
-        let data = self.read_measurement()?;
+        self.check_init(delay)?;
 
-        Ok(data)
+        Ok(Dht20 {
+            i2c: self.i2c,
+            address: self.address,
+            poll: self.poll,
+        })
     }
 
     // polls the sensor to determine its initialization state
     fn check_init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), DHT20Error<E>> {
-        let mut buffer = [0u8; 1]; // set up a buffer to hold response word (byte)
-
-        // Send check_status opcode
-        self.i2c
-            .write_read(self.address, &[OpCode::CheckStatus as u8], &mut buffer)?;
-
-        let status = buffer[0];
-
-        // Ensure status is 0x18
-        if (status & 0x18) != 0x18 {
+        // If calibration hasn't come up, run the reset-register sequence and
+        // check again; a sensor that never calibrates is unusable.
+        if !self.read_status()?.calibration_enabled() {
             for reg in RESET_REGISTERS.iter() {
                 self.reset_register(delay, *reg)?;
             }
+
+            if !self.read_status()?.calibration_enabled() {
+                return Err(DHT20Error::NotCalibrated);
+            }
         }
 
         // wait 10ms for the sensor to stabilize (prerequisite for taking a measurement)
         delay.delay_ms(10);
 
-        // initialized
-        self.initialized = true;
         Ok(())
     }
 
+    // read and decode the sensor's status byte
+    fn read_status(&mut self) -> Result<Status, DHT20Error<E>> {
+        let mut buffer = [0u8; 1]; // set up a buffer to hold response word (byte)
+
+        self.i2c
+            .write_read(self.address, &[OpCode::CheckStatus as u8], &mut buffer)?;
+
+        Ok(Status::from_byte(buffer[0]))
+    }
+
     // reset the sensor; undocumented by aosong, following along with
     // code from https://github.com/RobTillaart/DHT20/ as it's the best available documentation.
     fn reset_register<D: DelayNs>(&mut self, delay: &mut D, reg: u8) -> Result<(), DHT20Error<E>> {
@@ -168,6 +217,53 @@ where
 
         Ok(())
     }
+}
+
+pub struct Dht20<I2C> {
+    i2c: I2C,
+    address: SevenBitAddress,
+    poll: PollConfig,
+}
+
+impl<I2C, E> Dht20<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    // request a reading from the sensor
+    // returns a DHTReading struct containing the temperature and humidity
+    pub fn take_reading<D: DelayNs>(&mut self, delay: &mut D) -> Result<DHTReading, DHT20Error<E>> {
+        self.trigger_measurement(delay)?; // trigger the measurement
+
+        self.wait_for_ready(delay)?; // wait for measurement to be ready
+
+        let data = self.read_measurement()?;
+
+        // extract the humidity and temperature readings from the data
+        let (raw_humidity, raw_temperature) = utils::extract_readings(&data);
+
+        // convert the raw readings to percentage, Celsius
+        let humidity = utils::convert_humidity(raw_humidity);
+        let temperature = utils::convert_temperature(raw_temperature);
+
+        // return the readings as a DHTReading struct
+        Ok(DHTReading::new(temperature, humidity))
+    }
+
+    pub fn read_raw<D: DelayNs>(&mut self, delay: &mut D) -> Result<[u8; 6], DHT20Error<E>> {
+        self.trigger_measurement(delay)?; // trigger the measurement
+
+        self.wait_for_ready(delay)?; // wait for measurement to be ready
+
+        let data = self.read_measurement()?;
+
+        Ok(data)
+    }
+
+    // wrap this driver in an IIR smoothing layer. `coefficient` is the filter
+    // strength: 1 disables filtering, larger values smooth more aggressively.
+    pub fn with_filter(self, coefficient: u32) -> FilteredDht20<I2C> {
+        FilteredDht20::new(self, coefficient)
+    }
 
     // trigger a measurement
     fn trigger_measurement<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), DHT20Error<E>> {
@@ -184,18 +280,21 @@ where
     fn wait_for_ready<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), DHT20Error<E>> {
         let mut buffer = [0u8; 1]; // buffer to hold status word (1 byte)
 
-        // poll until ready
-        loop {
+        // poll until ready, but only up to the configured budget so a wedged
+        // sensor can't hang us forever
+        for _ in 0..self.poll.max_attempts {
             self.i2c
                 .write_read(self.address, &[OpCode::CheckStatus as u8], &mut buffer)?;
-            // buffer[0] means first (only) byte
-            // mask out all but the 7th bit (0x80); if it's 0, we're ready.
-            if buffer[0] & (OpCode::StatusReady as u8) == 0 {
+            // once the busy flag clears, the measurement is complete
+            if !Status::from_byte(buffer[0]).busy() {
                 return Ok(()); // measurement complete
             }
-            // otherwise, wait 10ms before polling again
-            delay.delay_ms(0);
+            // otherwise, back off before polling again
+            delay.delay_ms(self.poll.poll_interval_ms);
         }
+
+        // never became ready within the budget
+        Err(DHT20Error::Timeout)
     }
 
     // read the measurement values from the sensor
@@ -217,3 +316,71 @@ where
         Ok(buffer[..6].try_into().unwrap()) // convert slice to array
     }
 }
+
+// A raw reading paired with its IIR-smoothed counterpart, returned by
+// `FilteredDht20`. Callers can use whichever they prefer: the raw sample for
+// responsiveness, or the filtered one for a quieter signal.
+#[derive(Debug)]
+pub struct FilteredReading {
+    raw: DHTReading,
+    filtered: DHTReading,
+}
+
+impl FilteredReading {
+    // the unsmoothed reading straight from the sensor
+    pub fn raw(&self) -> &DHTReading {
+        &self.raw
+    }
+
+    // the IIR-smoothed reading
+    pub fn filtered(&self) -> &DHTReading {
+        &self.filtered
+    }
+}
+
+// Opt-in smoothing layer over a `Dht20`. Each `take_reading` updates running
+// filtered temperature and humidity state with a first-order IIR filter. The
+// state is seeded from the first raw reading so there is no warm-up ramp from
+// zero.
+pub struct FilteredDht20<I2C> {
+    inner: Dht20<I2C>,
+    coefficient: u32,
+    state: Option<(f32, f32)>, // filtered (temperature, humidity)
+}
+
+impl<I2C, E> FilteredDht20<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    // wrap a driver with the given IIR filter strength
+    pub fn new(inner: Dht20<I2C>, coefficient: u32) -> Self {
+        Self {
+            inner,
+            coefficient,
+            state: None,
+        }
+    }
+
+    // take a reading, returning both the raw sample and the filtered value
+    pub fn take_reading<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<FilteredReading, DHT20Error<E>> {
+        let raw = self.inner.take_reading(delay)?;
+
+        // seed from the first reading, then fold each subsequent sample in
+        let (temperature, humidity) = match self.state {
+            None => (raw.temperature, raw.humidity),
+            Some((t, h)) => (
+                utils::iir_step(t, raw.temperature, self.coefficient),
+                utils::iir_step(h, raw.humidity, self.coefficient),
+            ),
+        };
+        self.state = Some((temperature, humidity));
+
+        Ok(FilteredReading {
+            raw,
+            filtered: DHTReading::new(temperature, humidity),
+        })
+    }
+}
@@ -0,0 +1,226 @@
+use embedded_hal_async::delay::DelayNs; // for timing
+use embedded_hal_async::i2c::{I2c, SevenBitAddress};
+
+// internal
+use utils::compute_crc8; // CRC8 checksum helper function
+
+// The reading, error, status and polling-config types do no I/O, so the async
+// driver shares them with the sync one rather than defining parallel copies.
+use super::dht20::{DHTReading, DHT20Error, PollConfig, Status};
+
+#[repr(u8)] // represent as u8; permits casting to a byte
+enum OpCode {
+    CheckStatus = 0x71,
+    TriggerMeasurement = 0xAC,
+}
+
+const I2C_ADDRESS: SevenBitAddress = 0x38; // DHT20 default I2C address per datasheet
+const RESET_REGISTERS: [u8; 3] = [0x1B, 0x1C, 0x1E];
+
+// Uninitialized handle for the DHT20 sensor, async flavour. See the sync
+// `Dht20Uninit` for why initialization is a distinct type: `init` consumes
+// this handle and awaits the power-up sequence before yielding a `Dht20`.
+pub struct Dht20Uninit<I2C> {
+    i2c: I2C,
+    address: SevenBitAddress,
+    poll: PollConfig,
+}
+
+impl<I2C, E> Dht20Uninit<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    // constructor for the uninitialized handle
+    pub fn new(i2c: I2C) -> Self {
+        Self::with_config(i2c, PollConfig::default())
+    }
+
+    // constructor taking an explicit ready-polling budget, for callers that
+    // want to trade responsiveness against tolerance for a slow sensor
+    pub fn with_config(i2c: I2C, poll: PollConfig) -> Self {
+        Self {
+            i2c, // dependency injection; receive the I2C instance
+            address: I2C_ADDRESS,
+            poll,
+        }
+    }
+
+    // initialize the sensor, consuming the uninitialized handle and
+    // returning a ready-to-use `Dht20`
+    pub async fn init<D: DelayNs>(
+        mut self,
+        delay: &mut D,
+    ) -> Result<Dht20<I2C>, DHT20Error<E>> {
+        delay.delay_ms(100).await; // wait for sensor to power up, no less than 100ms
+
+        self.check_init(delay).await?;
+
+        Ok(Dht20 {
+            i2c: self.i2c,
+            address: self.address,
+            poll: self.poll,
+        })
+    }
+
+    // polls the sensor to determine its initialization state
+    async fn check_init<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), DHT20Error<E>> {
+        // If calibration hasn't come up, run the reset-register sequence and
+        // check again; a sensor that never calibrates is unusable.
+        if !self.read_status().await?.calibration_enabled() {
+            for reg in RESET_REGISTERS.iter() {
+                self.reset_register(delay, *reg).await?;
+            }
+
+            if !self.read_status().await?.calibration_enabled() {
+                return Err(DHT20Error::NotCalibrated);
+            }
+        }
+
+        // wait 10ms for the sensor to stabilize (prerequisite for taking a measurement)
+        delay.delay_ms(10).await;
+
+        Ok(())
+    }
+
+    // read and decode the sensor's status byte
+    async fn read_status(&mut self) -> Result<Status, DHT20Error<E>> {
+        let mut buffer = [0u8; 1]; // set up a buffer to hold response word (byte)
+
+        self.i2c
+            .write_read(self.address, &[OpCode::CheckStatus as u8], &mut buffer)
+            .await?;
+
+        Ok(Status::from_byte(buffer[0]))
+    }
+
+    // reset the sensor; undocumented by aosong, following along with
+    // code from https://github.com/RobTillaart/DHT20/ as it's the best available documentation.
+    async fn reset_register<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        reg: u8,
+    ) -> Result<(), DHT20Error<E>> {
+        let mut buffer = [0u8; 3]; // buffer to hold 3 response words (bytes)
+
+        // Write 0x00, 0x00 to the register - clear the values
+        self.i2c.write(self.address, &[reg, 0x00, 0x00]).await?;
+
+        // delay for stability's sake
+        delay.delay_ms(5).await;
+
+        // Read back 3 bytes from the register
+        self.i2c.write_read(self.address, &[reg], &mut buffer).await?;
+        delay.delay_ms(5).await;
+
+        // Write modified values back to register; we're OR-ing them w/ 0xB0.
+        // Undocumented, just copying from RobTillaart's code.
+        self.i2c
+            .write(self.address, &[0xB0 | reg, buffer[1], buffer[2]])
+            .await?;
+        delay.delay_ms(5).await;
+
+        Ok(())
+    }
+}
+
+pub struct Dht20<I2C> {
+    i2c: I2C,
+    address: SevenBitAddress,
+    poll: PollConfig,
+}
+
+impl<I2C, E> Dht20<I2C>
+where
+    I2C: I2c<Error = E>,
+{
+    // request a reading from the sensor
+    // returns a DHTReading struct containing the temperature and humidity
+    pub async fn take_reading<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<DHTReading, DHT20Error<E>> {
+        self.trigger_measurement(delay).await?; // trigger the measurement
+
+        self.wait_for_ready(delay).await?; // wait for measurement to be ready
+
+        let data = self.read_measurement().await?;
+
+        // extract the humidity and temperature readings from the data
+        let (raw_humidity, raw_temperature) = utils::extract_readings(&data);
+
+        // convert the raw readings to percentage, Celsius
+        let humidity = utils::convert_humidity(raw_humidity);
+        let temperature = utils::convert_temperature(raw_temperature);
+
+        // return the readings as a DHTReading struct
+        Ok(DHTReading::new(temperature, humidity))
+    }
+
+    pub async fn read_raw<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<[u8; 6], DHT20Error<E>> {
+        self.trigger_measurement(delay).await?; // trigger the measurement
+
+        self.wait_for_ready(delay).await?; // wait for measurement to be ready
+
+        let data = self.read_measurement().await?;
+
+        Ok(data)
+    }
+
+    // trigger a measurement
+    async fn trigger_measurement<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+    ) -> Result<(), DHT20Error<E>> {
+        // 0x33 and 0x00 are two argument bytes to be sent to the sensor when triggering a measurement.
+        let command = [OpCode::TriggerMeasurement as u8, 0x33, 0x00];
+        self.i2c.write(self.address, &command).await?;
+
+        delay.delay_ms(80).await; // wait 80ms per the datasheet (minimum time to ready)
+
+        Ok(())
+    }
+
+    // wait for measurement to be ready
+    async fn wait_for_ready<D: DelayNs>(&mut self, delay: &mut D) -> Result<(), DHT20Error<E>> {
+        let mut buffer = [0u8; 1]; // buffer to hold status word (1 byte)
+
+        // poll until ready, but only up to the configured budget so a wedged
+        // sensor can't hang us forever
+        for _ in 0..self.poll.max_attempts {
+            self.i2c
+                .write_read(self.address, &[OpCode::CheckStatus as u8], &mut buffer)
+                .await?;
+            // once the busy flag clears, the measurement is complete
+            if !Status::from_byte(buffer[0]).busy() {
+                return Ok(()); // measurement complete
+            }
+            // otherwise, yield and back off before polling again
+            delay.delay_ms(self.poll.poll_interval_ms).await;
+        }
+
+        // never became ready within the budget
+        Err(DHT20Error::Timeout)
+    }
+
+    // read the measurement values from the sensor
+    // these must be parsed before usage
+    async fn read_measurement(&mut self) -> Result<[u8; 6], DHT20Error<E>> {
+        let mut buffer = [0u8; 7]; // buffer to hold 6 data bytes and 1 CRC byte
+
+        // read 7 bytes from the sensor
+        self.i2c.read(self.address, &mut buffer).await?;
+
+        let crc = buffer[6]; // 7th byte is the CRC
+
+        // compute CRC8
+        let crc_check = compute_crc8(&buffer[..6]);
+        if crc != crc_check {
+            return Err(DHT20Error::CrcMismatch);
+        }
+        // return the 6 data bytes
+        Ok(buffer[..6].try_into().unwrap()) // convert slice to array
+    }
+}
@@ -0,0 +1,6 @@
+pub mod dht20;
+
+// Async mirror of the driver, built on embedded-hal-async. Gated behind the
+// `async` feature so the sync driver has no dependency on an async executor.
+#[cfg(feature = "async")]
+pub mod dht20_async;
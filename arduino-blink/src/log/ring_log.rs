@@ -0,0 +1,272 @@
+use crate::dht20::dht20::DHTReading;
+
+// Milliseconds since some fixed epoch chosen by the caller (e.g. boot). The
+// log only ever stores and hands back these, so the origin is the caller's
+// concern.
+pub type Timestamp = u64;
+
+// Fixed-capacity circular log of timestamped readings, sized at compile time
+// by the const-generic `N`. Pushing past capacity overwrites the oldest
+// entry, so the buffer always holds the most recent `N` samples. Running
+// min/max/mean over temperature and humidity are maintained as samples enter
+// and leave the window: the sums (and hence the means) update in O(1), while
+// min/max only fall back to an O(N) rescan when the evicted sample was itself
+// the current extreme.
+//
+// This is `no_std`-friendly and `alloc`-free: the storage is an inline array.
+pub struct ReadingLog<const N: usize> {
+    entries: [Option<(Timestamp, DHTReading)>; N],
+    head: usize, // index of the oldest entry
+    len: usize,  // number of populated entries
+    temperature_sum: f32,
+    humidity_sum: f32,
+    temperature_min: f32,
+    temperature_max: f32,
+    humidity_min: f32,
+    humidity_max: f32,
+}
+
+impl<const N: usize> Default for ReadingLog<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> ReadingLog<N> {
+    // construct an empty log
+    pub fn new() -> Self {
+        Self {
+            entries: core::array::from_fn(|_| None),
+            head: 0,
+            len: 0,
+            temperature_sum: 0.0,
+            humidity_sum: 0.0,
+            temperature_min: 0.0,
+            temperature_max: 0.0,
+            humidity_min: 0.0,
+            humidity_max: 0.0,
+        }
+    }
+
+    // number of readings currently held
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // true when no readings have been pushed
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // total capacity, i.e. the const-generic `N`
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    // append a reading. Once the buffer is full the oldest entry is evicted to
+    // make room, keeping the window pinned to the most recent `N` samples.
+    pub fn push(&mut self, timestamp: Timestamp, reading: DHTReading) {
+        let temperature = reading.temperature_celsius();
+        let humidity = reading.humidity();
+
+        if self.len == N {
+            // full: evict the oldest entry at `head` before reusing its slot
+            let mut dropped_extreme = false;
+            if let Some((_, old)) = self.entries[self.head].take() {
+                self.temperature_sum -= old.temperature_celsius();
+                self.humidity_sum -= old.humidity();
+                // an eviction only disturbs an extreme if the departed sample
+                // was itself that extreme; otherwise the bounds still hold
+                dropped_extreme = old.temperature_celsius() == self.temperature_min
+                    || old.temperature_celsius() == self.temperature_max
+                    || old.humidity() == self.humidity_min
+                    || old.humidity() == self.humidity_max;
+            }
+            self.entries[self.head] = Some((timestamp, reading));
+            self.head = (self.head + 1) % N;
+
+            self.temperature_sum += temperature;
+            self.humidity_sum += humidity;
+
+            if dropped_extreme {
+                // the reigning extreme left the window, so rescan to rebuild it
+                self.recompute_extremes();
+            } else {
+                // bounds survived the eviction; fold the new sample in as usual
+                self.temperature_min = self.temperature_min.min(temperature);
+                self.temperature_max = self.temperature_max.max(temperature);
+                self.humidity_min = self.humidity_min.min(humidity);
+                self.humidity_max = self.humidity_max.max(humidity);
+            }
+        } else {
+            let index = (self.head + self.len) % N;
+            self.entries[index] = Some((timestamp, reading));
+            self.len += 1;
+
+            self.temperature_sum += temperature;
+            self.humidity_sum += humidity;
+            // insertion can only widen the extremes, an O(1) update
+            if self.len == 1 {
+                self.temperature_min = temperature;
+                self.temperature_max = temperature;
+                self.humidity_min = humidity;
+                self.humidity_max = humidity;
+            } else {
+                self.temperature_min = self.temperature_min.min(temperature);
+                self.temperature_max = self.temperature_max.max(temperature);
+                self.humidity_min = self.humidity_min.min(humidity);
+                self.humidity_max = self.humidity_max.max(humidity);
+            }
+        }
+    }
+
+    // mean temperature over the window, or None when empty
+    pub fn temperature_mean(&self) -> Option<f32> {
+        (self.len > 0).then(|| self.temperature_sum / self.len as f32)
+    }
+
+    // mean humidity over the window, or None when empty
+    pub fn humidity_mean(&self) -> Option<f32> {
+        (self.len > 0).then(|| self.humidity_sum / self.len as f32)
+    }
+
+    // lowest temperature in the window, or None when empty
+    pub fn temperature_min(&self) -> Option<f32> {
+        (self.len > 0).then_some(self.temperature_min)
+    }
+
+    // highest temperature in the window, or None when empty
+    pub fn temperature_max(&self) -> Option<f32> {
+        (self.len > 0).then_some(self.temperature_max)
+    }
+
+    // lowest humidity in the window, or None when empty
+    pub fn humidity_min(&self) -> Option<f32> {
+        (self.len > 0).then_some(self.humidity_min)
+    }
+
+    // highest humidity in the window, or None when empty
+    pub fn humidity_max(&self) -> Option<f32> {
+        (self.len > 0).then_some(self.humidity_max)
+    }
+
+    // iterate over the held entries from oldest to newest
+    pub fn iter(&self) -> Iter<'_, N> {
+        Iter {
+            log: self,
+            offset: 0,
+        }
+    }
+
+    // rescan the window to rebuild the min/max extremes. Only called on
+    // eviction, where the departed sample might have been the extreme.
+    fn recompute_extremes(&mut self) {
+        let mut iter = self.iter();
+        if let Some((_, first)) = iter.next() {
+            let mut t_min = first.temperature_celsius();
+            let mut t_max = t_min;
+            let mut h_min = first.humidity();
+            let mut h_max = h_min;
+
+            for (_, reading) in iter {
+                let t = reading.temperature_celsius();
+                let h = reading.humidity();
+                t_min = t_min.min(t);
+                t_max = t_max.max(t);
+                h_min = h_min.min(h);
+                h_max = h_max.max(h);
+            }
+
+            self.temperature_min = t_min;
+            self.temperature_max = t_max;
+            self.humidity_min = h_min;
+            self.humidity_max = h_max;
+        }
+    }
+}
+
+// Chronological iterator over a `ReadingLog`, yielding each entry's timestamp
+// alongside a reference to its reading.
+pub struct Iter<'a, const N: usize> {
+    log: &'a ReadingLog<N>,
+    offset: usize,
+}
+
+impl<'a, const N: usize> Iterator for Iter<'a, N> {
+    type Item = (Timestamp, &'a DHTReading);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset >= self.log.len {
+            return None;
+        }
+        let index = (self.log.head + self.offset) % N;
+        self.offset += 1;
+        self.log.entries[index]
+            .as_ref()
+            .map(|(timestamp, reading)| (*timestamp, reading))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iter_chronological_after_overwrite() {
+        // a capacity-3 log, pushed four times, should hold the last three
+        // readings in chronological order with the oldest evicted
+        let mut log: ReadingLog<3> = ReadingLog::new();
+        for i in 0..4u64 {
+            log.push(i, DHTReading::new(i as f32, 0.0));
+        }
+
+        let timestamps: [Timestamp; 3] = {
+            let mut out = [0; 3];
+            for (slot, (ts, _)) in out.iter_mut().zip(log.iter()) {
+                *slot = ts;
+            }
+            out
+        };
+
+        assert_eq!(log.len(), 3);
+        assert_eq!(timestamps, [1, 2, 3]); // entry 0 was overwritten
+    }
+
+    #[test]
+    fn test_mean_after_wrap() {
+        // after wrapping, the means cover only the retained window
+        let mut log: ReadingLog<3> = ReadingLog::new();
+        for i in 0..4u64 {
+            log.push(i, DHTReading::new(i as f32 * 10.0, i as f32));
+        }
+
+        // retained readings are temperatures 10, 20, 30 and humidities 1, 2, 3
+        assert_eq!(log.temperature_mean(), Some(20.0));
+        assert_eq!(log.humidity_mean(), Some(2.0));
+    }
+
+    #[test]
+    fn test_eviction_of_current_extreme_rescans() {
+        // push a reading that is the maximum, then evict it and confirm the
+        // max drops back to the next-highest retained sample
+        let mut log: ReadingLog<3> = ReadingLog::new();
+        log.push(0, DHTReading::new(100.0, 0.0)); // the max
+        log.push(1, DHTReading::new(20.0, 0.0));
+        log.push(2, DHTReading::new(30.0, 0.0));
+
+        assert_eq!(log.temperature_max(), Some(100.0));
+
+        // this push evicts the 100.0 reading at the head
+        log.push(3, DHTReading::new(40.0, 0.0));
+
+        assert_eq!(log.temperature_max(), Some(40.0));
+    }
+
+    #[test]
+    fn test_empty_stats_are_none() {
+        let log: ReadingLog<4> = ReadingLog::new();
+        assert!(log.is_empty());
+        assert_eq!(log.temperature_mean(), None);
+        assert_eq!(log.temperature_max(), None);
+    }
+}
@@ -75,6 +75,58 @@ pub fn convert_temperature(temperature: u32) -> f32 {
     temperature * 200.0 - 50.0
 }
 
+// computes the dew point in degrees Celsius from a temperature in degrees
+// Celsius and a relative humidity percentage, using the Magnus-Tetens
+// approximation. Relative humidity at or below zero has no defined dew point,
+// so we clamp it to a small floor to keep the logarithm finite rather than
+// returning NaN.
+pub fn dew_point_celsius(temperature_c: f32, humidity: f32) -> f32 {
+    const A: f32 = 17.625;
+    const B: f32 = 243.04;
+
+    let rh = if humidity <= 0.0 { 0.01 } else { humidity };
+
+    let gamma = libm::logf(rh / 100.0) + (A * temperature_c) / (B + temperature_c);
+    (B * gamma) / (A - gamma)
+}
+
+// computes the heat index ("feels like" temperature) in degrees Fahrenheit
+// from a temperature in degrees Fahrenheit and a relative humidity
+// percentage, using the Rothfusz regression. The full regression is only
+// valid for warm, humid conditions, so below 80°F / 40% RH we fall back to
+// the simpler Steadman average.
+pub fn heat_index_fahrenheit(temperature_f: f32, humidity: f32) -> f32 {
+    let tf = temperature_f;
+    let r = humidity;
+
+    // simple average, valid outside the regression's warm/humid domain
+    let simple = 0.5 * (tf + 61.0 + (tf - 68.0) * 1.2 + r * 0.094);
+
+    if tf < 80.0 || r < 40.0 {
+        return simple;
+    }
+
+    -42.379 + 2.04901523 * tf + 10.14333127 * r
+        - 0.22475541 * tf * r
+        - 6.83783e-3 * tf * tf
+        - 5.481717e-2 * r * r
+        + 1.22874e-3 * tf * tf * r
+        + 8.5282e-4 * tf * r * r
+        - 1.99e-6 * tf * tf * r * r
+}
+
+// applies one step of a first-order IIR low-pass filter:
+// `x_filtered = x_filtered + (x_raw - x_filtered) / c`.
+// `coefficient` is an integer filter strength: c = 1 passes the raw sample
+// through unchanged, larger c yields a smoother but slower response.
+pub fn iir_step(filtered: f32, raw: f32, coefficient: u32) -> f32 {
+    if coefficient <= 1 {
+        raw
+    } else {
+        filtered + (raw - filtered) / coefficient as f32
+    }
+}
+
 #[cfg(test)]
 mod tests_crc8 {
     use super::*;
@@ -139,6 +191,79 @@ mod tests_convert_temperature {
     }
 }
 
+#[cfg(test)]
+mod tests_iir_step {
+    use super::*;
+
+    #[test]
+    fn test_iir_disabled_passes_raw_through() {
+        // c = 1 is an identity filter
+        assert_eq!(iir_step(10.0, 42.0, 1), 42.0);
+    }
+
+    #[test]
+    fn test_iir_step_converges_to_step_input() {
+        // driving a constant input through the filter should converge to it
+        let mut filtered = 0.0;
+        for _ in 0..200 {
+            filtered = iir_step(filtered, 100.0, 4);
+        }
+        assert!((filtered - 100.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_iir_step_single_step_fraction() {
+        // one step moves 1/c of the way from the old value to the new
+        let filtered = iir_step(0.0, 100.0, 4);
+        assert_eq!(filtered, 25.0);
+    }
+}
+
+#[cfg(test)]
+mod tests_dew_point {
+    use super::*;
+
+    #[test]
+    fn test_dew_point_25c_50rh() {
+        // 25°C at 50% RH has a dew point of roughly 13.9°C
+        let dew_point = dew_point_celsius(25.0, 50.0);
+        assert!((dew_point - 13.86).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_dew_point_saturated() {
+        // at 100% RH the dew point equals the temperature
+        let dew_point = dew_point_celsius(20.0, 100.0);
+        assert!((dew_point - 20.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_dew_point_nonpositive_humidity_is_finite() {
+        // zero humidity is clamped rather than producing a NaN
+        assert!(dew_point_celsius(25.0, 0.0).is_finite());
+    }
+}
+
+#[cfg(test)]
+mod tests_heat_index {
+    use super::*;
+
+    #[test]
+    fn test_heat_index_regression() {
+        // 90°F at 70% RH falls in the Rothfusz domain; reference ~106°F
+        let hi = heat_index_fahrenheit(90.0, 70.0);
+        assert!((hi - 105.9).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_heat_index_simple_fallback() {
+        // 75°F / 30% RH is below the regression threshold, so the simple
+        // average applies
+        let hi = heat_index_fahrenheit(75.0, 30.0);
+        assert!((hi - 73.61).abs() < 0.1);
+    }
+}
+
 #[cfg(test)]
 mod tests_extract_readings {
     use super::*;